@@ -0,0 +1,233 @@
+//! Subcommand-based CLI for astrbox.
+//!
+//! This is invoked two ways: as the compiled `astrbox` binary's `main`, and
+//! as `core.main_cli(sys.argv)` from `python -m astrbox`, so the packager
+//! gets one native entrypoint instead of juggling separate Rust and Python
+//! launch paths.
+
+use crate::age;
+use crate::host::PluginHost;
+use crate::openpgp;
+use clap::{Parser, Subcommand};
+use pyo3::Python;
+use std::fs;
+
+#[derive(Parser)]
+#[command(name = "astrbox", about = "AstrBot native runtime and CLI")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the bot. No runtime is configured for this command: it prints a
+    /// notice and exits with status 0.
+    Run,
+    /// Manage plugins.
+    Plugin {
+        #[command(subcommand)]
+        command: PluginCommand,
+    },
+    /// Manage encrypted secrets.
+    Secrets {
+        #[command(subcommand)]
+        command: SecretsCommand,
+    },
+    /// Print the astrbox version.
+    Version,
+}
+
+#[derive(Subcommand)]
+enum PluginCommand {
+    /// Load a plugin source file into a throwaway host to sanity-check it compiles.
+    Install { path: String },
+    /// List plugin source files in a directory.
+    List { dir: String },
+    /// Verify a plugin archive's detached signature against a keyring.
+    Verify {
+        path: String,
+        signature: String,
+        keyring: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum SecretsCommand {
+    /// Encrypt a file in place to one or more age recipients.
+    Encrypt {
+        path: String,
+        #[arg(required = true)]
+        recipients: Vec<String>,
+    },
+    /// Decrypt a file in place with an age identity.
+    Decrypt { path: String, identity: String },
+}
+
+/// Exit codes returned to the shell. Mirrors the conventional sysexits.h
+/// ranges closely enough for packagers without pulling in the whole table.
+mod exit_code {
+    pub const OK: i32 = 0;
+    pub const USAGE: i32 = 64;
+    pub const DATA_ERR: i32 = 65;
+    pub const SOFTWARE: i32 = 70;
+}
+
+/// A subcommand failure, tagged with the exit code it should produce.
+/// Bad/missing input (files, keys, signatures, passphrases) is
+/// `exit_code::DATA_ERR`; anything that shouldn't be reachable from user
+/// input alone is `exit_code::SOFTWARE`.
+struct Failure {
+    code: i32,
+    message: String,
+}
+
+impl Failure {
+    fn data(message: impl Into<String>) -> Self {
+        Failure {
+            code: exit_code::DATA_ERR,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Parse `args` (including the conventional argv[0]) and run the matching
+/// subcommand, returning a process exit code rather than calling
+/// `std::process::exit` so both the binary and the Python entrypoint can
+/// propagate it themselves.
+pub fn run(args: Vec<String>) -> i32 {
+    let cli = match Cli::try_parse_from(args) {
+        Ok(cli) => cli,
+        Err(err) => {
+            // clap's Error already renders the appropriate usage/help text.
+            let _ = err.print();
+            return if err.use_stderr() {
+                exit_code::USAGE
+            } else {
+                exit_code::OK
+            };
+        }
+    };
+
+    match dispatch(cli.command) {
+        Ok(code) => code,
+        Err(failure) => {
+            eprintln!("astrbox: {failure}");
+            failure.code
+        }
+    }
+}
+
+fn dispatch(command: Command) -> Result<i32, Failure> {
+    match command {
+        Command::Run => {
+            println!("astrbox: no runtime configured yet, nothing to run");
+            Ok(exit_code::OK)
+        }
+        Command::Version => {
+            println!("astrbox {}", env!("CARGO_PKG_VERSION"));
+            Ok(exit_code::OK)
+        }
+        Command::Plugin { command } => dispatch_plugin(command),
+        Command::Secrets { command } => dispatch_secrets(command),
+    }
+}
+
+fn dispatch_plugin(command: PluginCommand) -> Result<i32, Failure> {
+    match command {
+        PluginCommand::Install { path } => {
+            let code = fs::read_to_string(&path)
+                .map_err(|e| Failure::data(format!("reading {path}: {e}")))?;
+            Python::attach(|py| {
+                let mut host = PluginHost::new();
+                let name = plugin_name_from_path(&path);
+                host.load_source(py, name, code)
+                    .map_err(|e| Failure::data(e.to_string()))?;
+                println!("ok: {path} compiles and exports HANDLERS");
+                Ok(exit_code::OK)
+            })
+        }
+        PluginCommand::List { dir } => {
+            let mut count = 0;
+            for entry in
+                fs::read_dir(&dir).map_err(|e| Failure::data(format!("reading {dir}: {e}")))?
+            {
+                let entry = entry.map_err(|e| Failure::data(e.to_string()))?;
+                if entry.path().extension().and_then(|e| e.to_str()) == Some("py") {
+                    println!("{}", entry.path().display());
+                    count += 1;
+                }
+            }
+            if count == 0 {
+                println!("(no plugin sources found in {dir})");
+            }
+            Ok(exit_code::OK)
+        }
+        PluginCommand::Verify {
+            path,
+            signature,
+            keyring,
+        } => {
+            let data =
+                fs::read(&path).map_err(|e| Failure::data(format!("reading {path}: {e}")))?;
+            let signature = fs::read_to_string(&signature)
+                .map_err(|e| Failure::data(format!("reading {signature}: {e}")))?;
+            let keyring_text = fs::read_to_string(&keyring)
+                .map_err(|e| Failure::data(format!("reading {keyring}: {e}")))?;
+            let keyring = openpgp::Keyring::from_armored(&keyring_text)
+                .map_err(|e| Failure::data(format!("parsing keyring: {e}")))?;
+            match openpgp::verify_detached(&data, &signature, &keyring) {
+                Ok(fingerprint) => {
+                    println!("signed by {fingerprint}");
+                    Ok(exit_code::OK)
+                }
+                Err(e) => Err(Failure::data(format!("signature verification failed: {e}"))),
+            }
+        }
+    }
+}
+
+fn dispatch_secrets(command: SecretsCommand) -> Result<i32, Failure> {
+    match command {
+        SecretsCommand::Encrypt { path, recipients } => {
+            let recipients: Result<Vec<age::Recipient>, _> = recipients
+                .iter()
+                .map(|r| age::Recipient::from_bech32(r))
+                .collect();
+            let recipients =
+                recipients.map_err(|e| Failure::data(format!("parsing recipient: {e}")))?;
+            let plaintext =
+                fs::read(&path).map_err(|e| Failure::data(format!("reading {path}: {e}")))?;
+            fs::write(&path, age::encrypt(&plaintext, &recipients))
+                .map_err(|e| Failure::data(format!("writing {path}: {e}")))?;
+            println!("encrypted {path} to {} recipient(s)", recipients.len());
+            Ok(exit_code::OK)
+        }
+        SecretsCommand::Decrypt { path, identity } => {
+            let identity = age::Identity::from_bech32(&identity)
+                .map_err(|e| Failure::data(format!("parsing identity: {e}")))?;
+            let ciphertext =
+                fs::read(&path).map_err(|e| Failure::data(format!("reading {path}: {e}")))?;
+            let plaintext = age::decrypt(&ciphertext, std::slice::from_ref(&identity))
+                .map_err(|e| Failure::data(format!("decrypting {path}: {e}")))?;
+            fs::write(&path, plaintext)
+                .map_err(|e| Failure::data(format!("writing {path}: {e}")))?;
+            println!("decrypted {path}");
+            Ok(exit_code::OK)
+        }
+    }
+}
+
+fn plugin_name_from_path(path: &str) -> String {
+    std::path::Path::new(path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("plugin")
+        .to_string()
+}
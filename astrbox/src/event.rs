@@ -0,0 +1,228 @@
+//! Typed bot message events.
+//!
+//! Python plugins keep passing ordinary dicts, but the performance-critical
+//! parts of AstrBot (routing, filtering, rate-limiting) extract a
+//! [`MessageEvent`] once at the edge and operate on validated Rust data with
+//! compile-time field access from then on. Invalid payloads raise
+//! `PyTypeError` naming the offending field rather than panicking deep
+//! inside a handler.
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::types::{PyDict, PyList};
+use pyo3::{Bound, FromPyObject, IntoPyObject, PyAny, PyErr, Python};
+
+/// One inbound bot message, already validated out of a raw `PyDict`.
+#[derive(Debug, Clone)]
+pub struct MessageEvent {
+    pub sender: Sender,
+    pub segments: Vec<MessageSegment>,
+    pub raw_text: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Sender {
+    pub id: String,
+    pub nickname: Option<String>,
+}
+
+/// A single piece of a (possibly multi-part) message.
+#[derive(Debug, Clone)]
+pub enum MessageSegment {
+    Text { text: String },
+    Image { url: String },
+    At { target: String },
+}
+
+fn field<'py>(dict: &pyo3::Bound<'py, PyDict>, name: &str) -> Result<pyo3::Bound<'py, PyAny>, PyErr> {
+    dict.get_item(name)?
+        .ok_or_else(|| PyTypeError::new_err(format!("missing field '{name}'")))
+}
+
+impl<'py> FromPyObject<'py> for Sender {
+    fn extract_bound(ob: &pyo3::Bound<'py, PyAny>) -> Result<Self, PyErr> {
+        let dict = ob
+            .downcast::<PyDict>()
+            .map_err(|_| PyTypeError::new_err("sender must be a dict"))?;
+        let id: String = field(dict, "id")?
+            .extract()
+            .map_err(|_| PyTypeError::new_err("field 'id' must be a str"))?;
+        let nickname: Option<String> = match dict.get_item("nickname")? {
+            Some(v) if !v.is_none() => Some(
+                v.extract()
+                    .map_err(|_| PyTypeError::new_err("field 'nickname' must be a str"))?,
+            ),
+            _ => None,
+        };
+        Ok(Sender { id, nickname })
+    }
+}
+
+impl<'py> IntoPyObject<'py> for Sender {
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let dict = PyDict::new(py);
+        dict.set_item("id", self.id)?;
+        dict.set_item("nickname", self.nickname)?;
+        Ok(dict)
+    }
+}
+
+impl<'py> FromPyObject<'py> for MessageSegment {
+    fn extract_bound(ob: &pyo3::Bound<'py, PyAny>) -> Result<Self, PyErr> {
+        let dict = ob
+            .downcast::<PyDict>()
+            .map_err(|_| PyTypeError::new_err("message segment must be a dict"))?;
+        let tag: String = field(dict, "type")?
+            .extract()
+            .map_err(|_| PyTypeError::new_err("field 'type' must be a str"))?;
+        match tag.as_str() {
+            "text" => {
+                let text: String = field(dict, "text")?
+                    .extract()
+                    .map_err(|_| PyTypeError::new_err("field 'text' must be a str"))?;
+                Ok(MessageSegment::Text { text })
+            }
+            "image" => {
+                let url: String = field(dict, "url")?
+                    .extract()
+                    .map_err(|_| PyTypeError::new_err("field 'url' must be a str"))?;
+                Ok(MessageSegment::Image { url })
+            }
+            "at" => {
+                let target: String = field(dict, "target")?
+                    .extract()
+                    .map_err(|_| PyTypeError::new_err("field 'target' must be a str"))?;
+                Ok(MessageSegment::At { target })
+            }
+            other => Err(PyTypeError::new_err(format!(
+                "unknown message segment type '{other}'"
+            ))),
+        }
+    }
+}
+
+impl<'py> IntoPyObject<'py> for MessageSegment {
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let dict = PyDict::new(py);
+        match self {
+            MessageSegment::Text { text } => {
+                dict.set_item("type", "text")?;
+                dict.set_item("text", text)?;
+            }
+            MessageSegment::Image { url } => {
+                dict.set_item("type", "image")?;
+                dict.set_item("url", url)?;
+            }
+            MessageSegment::At { target } => {
+                dict.set_item("type", "at")?;
+                dict.set_item("target", target)?;
+            }
+        }
+        Ok(dict)
+    }
+}
+
+impl<'py> FromPyObject<'py> for MessageEvent {
+    fn extract_bound(ob: &pyo3::Bound<'py, PyAny>) -> Result<Self, PyErr> {
+        let dict = ob
+            .downcast::<PyDict>()
+            .map_err(|_| PyTypeError::new_err("message event must be a dict"))?;
+
+        let sender: Sender = field(dict, "sender")?
+            .extract()
+            .map_err(|e| PyTypeError::new_err(format!("field 'sender': {e}")))?;
+
+        let segments_list = field(dict, "segments")?;
+        let segments_list = segments_list
+            .downcast::<PyList>()
+            .map_err(|_| PyTypeError::new_err("field 'segments' must be a list"))?;
+        let mut segments = Vec::with_capacity(segments_list.len());
+        for (i, item) in segments_list.iter().enumerate() {
+            let segment: MessageSegment = item
+                .extract()
+                .map_err(|e| PyTypeError::new_err(format!("field 'segments[{i}]': {e}")))?;
+            segments.push(segment);
+        }
+
+        let raw_text: String = field(dict, "raw_text")?
+            .extract()
+            .map_err(|_| PyTypeError::new_err("field 'raw_text' must be a str"))?;
+
+        Ok(MessageEvent {
+            sender,
+            segments,
+            raw_text,
+        })
+    }
+}
+
+impl<'py> IntoPyObject<'py> for MessageEvent {
+    type Target = PyDict;
+    type Output = Bound<'py, Self::Target>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> Result<Self::Output, Self::Error> {
+        let dict = PyDict::new(py);
+        dict.set_item("sender", self.sender)?;
+        dict.set_item("segments", self.segments)?;
+        dict.set_item("raw_text", self.raw_text)?;
+        Ok(dict)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_field_names_the_field() {
+        Python::attach(|py| {
+            let sender = PyDict::new(py);
+            sender.set_item("nickname", "nick").unwrap();
+            let err = sender.extract::<Sender>().unwrap_err().to_string();
+            assert!(err.contains("'id'"), "unexpected message: {err}");
+        });
+    }
+
+    #[test]
+    fn wrong_typed_field_names_the_field() {
+        Python::attach(|py| {
+            let sender = PyDict::new(py);
+            sender.set_item("id", 42).unwrap();
+            let err = sender.extract::<Sender>().unwrap_err().to_string();
+            assert!(err.contains("'id'"), "unexpected message: {err}");
+        });
+    }
+
+    #[test]
+    fn unknown_segment_type_is_rejected() {
+        Python::attach(|py| {
+            let segment = PyDict::new(py);
+            segment.set_item("type", "sticker").unwrap();
+            let err = segment.extract::<MessageSegment>().unwrap_err().to_string();
+            assert!(err.contains("sticker"), "unexpected message: {err}");
+        });
+    }
+
+    #[test]
+    fn missing_event_field_is_reported_with_path() {
+        Python::attach(|py| {
+            let sender = PyDict::new(py);
+            sender.set_item("id", "u1").unwrap();
+
+            let event = PyDict::new(py);
+            event.set_item("sender", sender).unwrap();
+            event.set_item("raw_text", "hi").unwrap();
+            // "segments" is missing entirely.
+            let err = event.extract::<MessageEvent>().unwrap_err().to_string();
+            assert!(err.contains("'segments'"), "unexpected message: {err}");
+        });
+    }
+}
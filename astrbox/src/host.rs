@@ -0,0 +1,131 @@
+//! Runtime plugin loader built on `PyModule::from_code`.
+//!
+//! Plugins arrive as source text (over the network, from a database column,
+//! wherever) rather than as files on disk, so `PluginHost` compiles them
+//! in-process and keeps the resulting modules alive behind the GIL. A
+//! plugin's exported handlers are discovered by event type and dispatched
+//! through a single entry point, so one misbehaving plugin can't take the
+//! whole host down with it.
+
+use pyo3::exceptions::{PyKeyError, PyRuntimeError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::collections::HashMap;
+
+/// A loaded plugin: its compiled module plus the handlers it exported,
+/// keyed by the event type each handler declared interest in.
+struct LoadedPlugin {
+    module: Py<PyModule>,
+    handlers: HashMap<String, Py<PyAny>>,
+}
+
+/// Host for plugins compiled from source strings via `PyModule::from_code`.
+///
+/// A plugin module registers its handlers by exposing a module-level dict
+/// named `HANDLERS` that maps event type strings to callables, e.g.
+///
+/// ```python
+/// def on_message(payload):
+///     ...
+///
+/// HANDLERS = {"message": on_message}
+/// ```
+#[pyclass]
+pub struct PluginHost {
+    plugins: HashMap<String, LoadedPlugin>,
+}
+
+#[pymethods]
+impl PluginHost {
+    #[new]
+    pub fn new() -> Self {
+        PluginHost {
+            plugins: HashMap::new(),
+        }
+    }
+
+    /// Compile `code` as a module named `name` and register its handlers.
+    /// Replaces any previously loaded plugin with the same name, so this
+    /// also serves as the hot-reload path.
+    pub fn load_source(&mut self, py: Python<'_>, name: String, code: String) -> PyResult<()> {
+        let filename = format!("{name}.py");
+        let module = PyModule::from_code(py, &code, &filename, &name).map_err(|e| {
+            PyRuntimeError::new_err(format!("plugin '{name}' failed to compile/import: {e}"))
+        })?;
+
+        let handlers_dict = module
+            .getattr("HANDLERS")
+            .map_err(|_| {
+                PyRuntimeError::new_err(format!(
+                    "plugin '{name}' does not export a HANDLERS dict"
+                ))
+            })?
+            .downcast_into::<PyDict>()
+            .map_err(|_| {
+                PyRuntimeError::new_err(format!("plugin '{name}'.HANDLERS must be a dict"))
+            })?;
+
+        let mut handlers = HashMap::with_capacity(handlers_dict.len());
+        for (key, value) in handlers_dict.iter() {
+            let event_type: String = key.extract().map_err(|_| {
+                PyRuntimeError::new_err(format!(
+                    "plugin '{name}'.HANDLERS keys must be strings"
+                ))
+            })?;
+            handlers.insert(event_type, value.unbind());
+        }
+
+        self.plugins.insert(
+            name,
+            LoadedPlugin {
+                module: module.unbind(),
+                handlers,
+            },
+        );
+        Ok(())
+    }
+
+    /// Drop a previously loaded plugin. No-op if it was never loaded.
+    fn unload(&mut self, name: &str) {
+        self.plugins.remove(name);
+    }
+
+    /// List the names of currently loaded plugins.
+    fn loaded(&self) -> Vec<String> {
+        self.plugins.keys().cloned().collect()
+    }
+
+    /// Call every loaded plugin's handler for `event_type`, if it has one,
+    /// passing it `payload`. Returns a dict of plugin name -> handler
+    /// return value; plugins with no handler for this event type are left
+    /// out of the dict. A single plugin raising does not stop dispatch to
+    /// the others; its entry holds the string `"error: <exception>"` instead.
+    fn dispatch(
+        &self,
+        py: Python<'_>,
+        event_type: &str,
+        payload: Py<PyAny>,
+    ) -> PyResult<Py<PyDict>> {
+        let results = PyDict::new(py);
+        for (name, plugin) in &self.plugins {
+            let Some(handler) = plugin.handlers.get(event_type) else {
+                continue;
+            };
+            match handler.call1(py, (payload.clone_ref(py),)) {
+                Ok(value) => results.set_item(name, value)?,
+                Err(err) => {
+                    results.set_item(name, format!("error: {err}"))?;
+                }
+            }
+        }
+        Ok(results.unbind())
+    }
+
+    /// Fetch a plugin's compiled module, e.g. for introspection from Python.
+    fn module(&self, py: Python<'_>, name: &str) -> PyResult<Py<PyModule>> {
+        self.plugins
+            .get(name)
+            .map(|p| p.module.clone_ref(py))
+            .ok_or_else(|| PyKeyError::new_err(name.to_string()))
+    }
+}
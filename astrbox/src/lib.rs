@@ -1,6 +1,11 @@
 use pyo3::prelude::*;
 
+mod age;
 pub mod cli;
+mod event;
+mod host;
+mod openpgp;
+mod runtime;
 
 #[pymodule]
 mod core {
@@ -11,9 +16,194 @@ mod core {
         "Hello from astrbox!".to_string()
     }
 
+    /// Run the `astrbox` CLI with `args` (argv, including argv[0]) and
+    /// return its process exit code, so `python -m astrbox` can call this
+    /// instead of re-parsing arguments on the Python side.
+    #[pyfunction]
+    fn main_cli(args: Vec<String>) -> i32 {
+        crate::cli::run(args)
+    }
+
+    /// Hot-reloadable plugin loader built on `PyModule::from_code`; see
+    /// [`crate::host::PluginHost`] for the dispatch contract plugins must
+    /// follow.
+    #[pymodule_export]
+    use crate::host::PluginHost;
+
+    /// Encrypted secrets vault so bot tokens and API keys never touch disk
+    /// as plaintext. Implements the age-encryption.org/v1 format natively.
+    #[pymodule]
+    mod secrets {
+        use crate::age;
+        use pyo3::exceptions::PyValueError;
+        use pyo3::prelude::*;
+        use std::fs;
+
+        fn parse_recipients(recipients: Vec<String>) -> PyResult<Vec<age::Recipient>> {
+            recipients
+                .iter()
+                .map(|r| age::Recipient::from_bech32(r).map_err(|e| PyValueError::new_err(e.to_string())))
+                .collect()
+        }
+
+        fn parse_identities(identities: Vec<String>) -> PyResult<Vec<age::Identity>> {
+            identities
+                .iter()
+                .map(|i| age::Identity::from_bech32(i).map_err(|e| PyValueError::new_err(e.to_string())))
+                .collect()
+        }
 
+        /// Generate a fresh X25519 identity, returning `(identity, recipient)`
+        /// as `AGE-SECRET-KEY-1...` / `age1...` Bech32 strings.
+        #[pyfunction]
+        fn generate_identity() -> (String, String) {
+            let identity = age::Identity::generate();
+            let recipient = identity.to_public().to_bech32();
+            (identity.to_bech32(), recipient)
+        }
 
+        /// Encrypt `data` in memory to one or more recipient public keys.
+        #[pyfunction]
+        fn encrypt_bytes(data: &[u8], recipients: Vec<String>) -> PyResult<Vec<u8>> {
+            let recipients = parse_recipients(recipients)?;
+            Ok(age::encrypt(data, &recipients))
+        }
 
+        /// Decrypt `data` using the first identity whose stanza matches.
+        #[pyfunction]
+        fn decrypt_bytes(data: &[u8], identities: Vec<String>) -> PyResult<Vec<u8>> {
+            let identities = parse_identities(identities)?;
+            age::decrypt(data, &identities).map_err(|e| PyValueError::new_err(e.to_string()))
+        }
+
+        /// Encrypt `data` in memory under a scrypt-wrapped passphrase.
+        #[pyfunction]
+        #[pyo3(signature = (data, passphrase, work_factor=age::DEFAULT_SCRYPT_LOG_N))]
+        fn encrypt_bytes_passphrase(data: &[u8], passphrase: &str, work_factor: u8) -> Vec<u8> {
+            age::encrypt_passphrase(data, passphrase, work_factor)
+        }
+
+        /// Decrypt passphrase-protected `data` produced by `encrypt_bytes_passphrase`.
+        #[pyfunction]
+        fn decrypt_bytes_passphrase(data: &[u8], passphrase: &str) -> PyResult<Vec<u8>> {
+            age::decrypt_passphrase(data, passphrase).map_err(|e| PyValueError::new_err(e.to_string()))
+        }
+
+        /// Read `path`, encrypt it to `recipients`, and write the result back to `path`.
+        #[pyfunction]
+        fn encrypt_file(path: &str, recipients: Vec<String>) -> PyResult<()> {
+            let recipients = parse_recipients(recipients)?;
+            let plaintext = fs::read(path)?;
+            fs::write(path, age::encrypt(&plaintext, &recipients))?;
+            Ok(())
+        }
+
+        /// Read `path`, decrypt it with `identity`, and return the plaintext bytes.
+        #[pyfunction]
+        fn decrypt_file(path: &str, identity: &str) -> PyResult<Vec<u8>> {
+            let identity = age::Identity::from_bech32(identity)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            let ciphertext = fs::read(path)?;
+            age::decrypt(&ciphertext, std::slice::from_ref(&identity))
+                .map_err(|e| PyValueError::new_err(e.to_string()))
+        }
+    }
 
+    /// Async message-pipeline bridge: a Tokio executor that fans inbound
+    /// bot events out to `async def` Python handlers with bounded
+    /// backpressure. There is one pipeline per process, matching the
+    /// one-inbound-stream-fans-out-to-many-adapters shape AstrBot uses it
+    /// for.
+    #[pymodule]
+    mod runtime {
+        use crate::runtime::Pipeline;
+        use pyo3::exceptions::PyRuntimeError;
+        use pyo3::prelude::*;
+        use std::sync::{Mutex, OnceLock};
 
+        static PIPELINE: OnceLock<Mutex<Option<Pipeline>>> = OnceLock::new();
+
+        fn slot() -> &'static Mutex<Option<Pipeline>> {
+            PIPELINE.get_or_init(|| Mutex::new(None))
+        }
+
+        /// Start the pipeline, routing every submitted event through
+        /// `handler` (an `async def`) with at most `concurrency` handler
+        /// calls in flight at once. Replaces any previously running
+        /// pipeline.
+        #[pyfunction]
+        #[pyo3(signature = (handler, concurrency, queue_depth=concurrency * 4))]
+        fn spawn_pipeline(handler: Py<PyAny>, concurrency: usize, queue_depth: usize) -> PyResult<()> {
+            let pipeline = Pipeline::spawn(handler, concurrency, queue_depth)
+                .map_err(|e| PyRuntimeError::new_err(format!("failed to start pipeline: {e}")))?;
+            *slot().lock().unwrap() = Some(pipeline);
+            Ok(())
+        }
+
+        /// Push `event` into the running pipeline and return an awaitable
+        /// that resolves once a handler call has completed.
+        #[pyfunction]
+        fn submit(py: Python<'_>, event: Py<PyAny>) -> PyResult<Py<PyAny>> {
+            let guard = slot().lock().unwrap();
+            let pipeline = guard
+                .as_ref()
+                .ok_or_else(|| PyRuntimeError::new_err("pipeline has not been started"))?;
+            pipeline.submit(py, event)
+        }
+
+        /// Stop accepting new events and wait for in-flight handlers to
+        /// finish. No-op if no pipeline is running.
+        #[pyfunction]
+        fn shutdown(py: Python<'_>) {
+            if let Some(pipeline) = slot().lock().unwrap().take() {
+                pipeline.shutdown(py);
+            }
+        }
+    }
+
+    /// Plugin package signature verification, turning `core` into a trust
+    /// gate for third-party extensions. Built on a minimal OpenPGP reader.
+    #[pymodule]
+    mod signing {
+        use crate::openpgp;
+        use pyo3::create_exception;
+        use pyo3::exceptions::PyException;
+        use pyo3::prelude::*;
+
+        create_exception!(signing, SignatureError, PyException);
+
+        /// Verify `signature` (an ASCII-armored detached OpenPGP signature)
+        /// over `data` against the trusted keys in `keyring` (an
+        /// ASCII-armored public keyring). Returns the signer's fingerprint
+        /// as lowercase hex on success, raises `SignatureError` otherwise.
+        #[pyfunction]
+        fn verify_detached(py: Python<'_>, data: &[u8], signature: &str, keyring: &str) -> PyResult<String> {
+            let keyring = openpgp::Keyring::from_armored(keyring)
+                .map_err(|e| SignatureError::new_err(e.to_string()))?;
+            py.allow_threads(|| openpgp::verify_detached(data, signature, &keyring))
+                .map_err(|e| SignatureError::new_err(e.to_string()))
+        }
+
+        /// Sign `data` with an Ed25519 secret key, returning an ASCII-armored
+        /// detached signature. `secret_key` is the 32-byte raw seed.
+        /// `created` is the signature's creation time as Unix seconds;
+        /// defaults to the current time if omitted, since
+        /// `verify_detached` rejects signatures missing or dated in the
+        /// future.
+        #[pyfunction]
+        #[pyo3(signature = (data, secret_key, created=None))]
+        fn sign_detached(data: &[u8], secret_key: &[u8], created: Option<u32>) -> PyResult<String> {
+            let seed: [u8; 32] = secret_key
+                .try_into()
+                .map_err(|_| SignatureError::new_err("secret_key must be exactly 32 bytes"))?;
+            let signing_key = ed25519_dalek::SigningKey::from_bytes(&seed);
+            let created = created.unwrap_or_else(|| {
+                std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_secs() as u32)
+                    .unwrap_or(0)
+            });
+            Ok(openpgp::sign_detached(data, &signing_key, created))
+        }
+    }
 }
@@ -0,0 +1,587 @@
+//! Minimal OpenPGP (RFC 4880) reader used to verify detached signatures over
+//! plugin archives. Only a small subset is implemented: ASCII-armor removal,
+//! public key packet parsing (RSA + Ed25519/EdDSA), detached signature packet
+//! verification, and producing armored detached Ed25519 signatures with
+//! [`sign_detached`]. Signing with RSA keys and key material other than
+//! signing keys are not supported.
+
+use ed25519_dalek::{Signature as EdSignature, Verifier, VerifyingKey};
+use rsa::pkcs1v15::Signature as RsaSignature;
+use rsa::pkcs1v15::VerifyingKey as RsaVerifyingKey;
+use rsa::signature::hazmat::PrehashVerifier;
+use rsa::RsaPublicKey;
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum PgpError {
+    Armor(&'static str),
+    Packet(&'static str),
+    UnknownIssuer,
+    UnsupportedAlgorithm(u8),
+    BadSignature,
+    /// The mandatory creation-time hashed subpacket was missing.
+    MissingCreationTime,
+    /// The signature's creation time is after `now` (allowing for a small
+    /// clock-skew tolerance), so it cannot have been produced yet.
+    FutureDated { created: u32, now: u32 },
+}
+
+impl fmt::Display for PgpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PgpError::Armor(why) => write!(f, "bad ASCII armor: {why}"),
+            PgpError::Packet(why) => write!(f, "malformed OpenPGP packet: {why}"),
+            PgpError::UnknownIssuer => write!(f, "signature issuer key not found in keyring"),
+            PgpError::UnsupportedAlgorithm(id) => {
+                write!(f, "unsupported public-key algorithm id {id}")
+            }
+            PgpError::BadSignature => write!(f, "signature did not verify"),
+            PgpError::MissingCreationTime => {
+                write!(f, "signature is missing its creation-time subpacket")
+            }
+            PgpError::FutureDated { created, now } => write!(
+                f,
+                "signature creation time {created} is after the current time {now}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PgpError {}
+
+enum KeyMaterial {
+    Ed25519(VerifyingKey),
+    Rsa(RsaPublicKey),
+}
+
+/// A single public key extracted from an armored keyring, indexed by the
+/// low 64 bits of its fingerprint (the OpenPGP "key ID").
+pub struct PublicKey {
+    pub key_id: [u8; 8],
+    pub fingerprint: [u8; 20],
+    material: KeyMaterial,
+}
+
+/// An ASCII-armored keyring of trusted maintainer keys.
+pub struct Keyring {
+    keys: Vec<PublicKey>,
+}
+
+impl Keyring {
+    pub fn from_armored(armored: &str) -> Result<Self, PgpError> {
+        let mut keys = Vec::new();
+        for block in dearmor_blocks(armored, "PGP PUBLIC KEY BLOCK")? {
+            keys.extend(parse_public_key_packets(&block)?);
+        }
+        Ok(Keyring { keys })
+    }
+
+    fn find(&self, key_id: &[u8; 8]) -> Option<&PublicKey> {
+        self.keys.iter().find(|k| &k.key_id == key_id)
+    }
+}
+
+struct Packet {
+    tag: u8,
+    body: Vec<u8>,
+}
+
+/// Strip `-----BEGIN <label>-----` / `-----END <label>-----` armor and
+/// base64-decode every block of the given label found in `text`.
+fn dearmor_blocks(text: &str, label: &str) -> Result<Vec<Vec<u8>>, PgpError> {
+    use base64::Engine;
+    let begin = format!("-----BEGIN {label}-----");
+    let end = format!("-----END {label}-----");
+    let mut blocks = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(&begin) {
+        let after_begin = &rest[start + begin.len()..];
+        let end_idx = after_begin
+            .find(&end)
+            .ok_or(PgpError::Armor("missing END line"))?;
+        let body_text = &after_begin[..end_idx];
+        let mut b64 = String::new();
+        for line in body_text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('=') || line.starts_with("Version:") {
+                continue;
+            }
+            b64.push_str(line);
+        }
+        let decoded = base64::engine::general_purpose::STANDARD
+            .decode(b64)
+            .map_err(|_| PgpError::Armor("invalid base64 body"))?;
+        blocks.push(decoded);
+        rest = &after_begin[end_idx + end.len()..];
+    }
+    if blocks.is_empty() {
+        return Err(PgpError::Armor("no matching armor block found"));
+    }
+    Ok(blocks)
+}
+
+/// Split an OpenPGP packet stream into (tag, body) pairs (old- and
+/// new-format packet headers only).
+fn read_packets(data: &[u8]) -> Result<Vec<Packet>, PgpError> {
+    let mut packets = Vec::new();
+    let mut pos = 0usize;
+    while pos < data.len() {
+        let first = data[pos];
+        if first & 0x80 == 0 {
+            return Err(PgpError::Packet("packet does not start with tag bit"));
+        }
+        pos += 1;
+        let (tag, len) = if first & 0x40 != 0 {
+            // New packet format.
+            let tag = first & 0x3f;
+            let l0 = *data.get(pos).ok_or(PgpError::Packet("truncated length"))?;
+            pos += 1;
+            let len = if l0 < 192 {
+                l0 as usize
+            } else if l0 < 224 {
+                let l1 = *data.get(pos).ok_or(PgpError::Packet("truncated length"))?;
+                pos += 1;
+                ((l0 as usize - 192) << 8) + l1 as usize + 192
+            } else {
+                return Err(PgpError::Packet("unsupported partial-length packet"));
+            };
+            (tag, len)
+        } else {
+            // Old packet format.
+            let tag = (first >> 2) & 0x0f;
+            let len_type = first & 0x03;
+            let len = match len_type {
+                0 => {
+                    let l = *data.get(pos).ok_or(PgpError::Packet("truncated length"))?;
+                    pos += 1;
+                    l as usize
+                }
+                1 => {
+                    let bytes = data
+                        .get(pos..pos + 2)
+                        .ok_or(PgpError::Packet("truncated length"))?;
+                    pos += 2;
+                    u16::from_be_bytes([bytes[0], bytes[1]]) as usize
+                }
+                _ => return Err(PgpError::Packet("unsupported packet length type")),
+            };
+            (tag, len)
+        };
+        let body = data
+            .get(pos..pos + len)
+            .ok_or(PgpError::Packet("packet body runs past end of data"))?
+            .to_vec();
+        pos += len;
+        packets.push(Packet { tag, body });
+    }
+    Ok(packets)
+}
+
+const TAG_PUBLIC_KEY: u8 = 6;
+const TAG_SIGNATURE: u8 = 2;
+
+const ALGO_RSA_ENCRYPT_SIGN: u8 = 1;
+const ALGO_EDDSA: u8 = 22;
+
+fn parse_public_key_packets(data: &[u8]) -> Result<Vec<PublicKey>, PgpError> {
+    let mut keys = Vec::new();
+    for packet in read_packets(data)? {
+        if packet.tag != TAG_PUBLIC_KEY {
+            continue;
+        }
+        keys.push(parse_public_key_packet(&packet.body)?);
+    }
+    Ok(keys)
+}
+
+/// Fetch `body[idx]`, or a `PgpError::Packet` instead of panicking on
+/// untrusted, possibly-truncated packet bodies.
+fn byte(body: &[u8], idx: usize) -> Result<u8, PgpError> {
+    body.get(idx).copied().ok_or(PgpError::Packet("packet body too short"))
+}
+
+/// Fetch `body[start..end]`, or a `PgpError::Packet` on an out-of-range slice.
+fn slice(body: &[u8], start: usize, end: usize) -> Result<&[u8], PgpError> {
+    body.get(start..end).ok_or(PgpError::Packet("packet body too short"))
+}
+
+/// Read a big-endian 16-bit length at `body[pos..pos+2]`.
+fn u16_at(body: &[u8], pos: usize) -> Result<usize, PgpError> {
+    let bytes = slice(body, pos, pos + 2)?;
+    Ok(u16::from_be_bytes([bytes[0], bytes[1]]) as usize)
+}
+
+fn parse_public_key_packet(body: &[u8]) -> Result<PublicKey, PgpError> {
+    if body.len() < 6 || body[0] != 4 {
+        return Err(PgpError::Packet("only v4 public keys are supported"));
+    }
+    let algo = body[5];
+    let material = match algo {
+        ALGO_EDDSA => {
+            let mut pos = 6usize;
+            let curve_len = byte(body, pos)? as usize;
+            pos += 1 + curve_len;
+            let mpi_bits = u16_at(body, pos)?;
+            pos += 2;
+            let mpi_bytes = (mpi_bits + 7) / 8;
+            let point = slice(body, pos, pos + mpi_bytes)?;
+            // Leading 0x40 prefix marks the native point encoding; drop it.
+            let raw = if point.first() == Some(&0x40) {
+                &point[1..]
+            } else {
+                point
+            };
+            if raw.len() != 32 {
+                return Err(PgpError::Packet("unexpected Ed25519 point length"));
+            }
+            let mut arr = [0u8; 32];
+            arr.copy_from_slice(raw);
+            let vk = VerifyingKey::from_bytes(&arr)
+                .map_err(|_| PgpError::Packet("invalid Ed25519 point"))?;
+            KeyMaterial::Ed25519(vk)
+        }
+        ALGO_RSA_ENCRYPT_SIGN => {
+            let mut pos = 6usize;
+            let n_bits = u16_at(body, pos)?;
+            pos += 2;
+            let n_bytes = (n_bits + 7) / 8;
+            let n = slice(body, pos, pos + n_bytes)?;
+            pos += n_bytes;
+            let e_bits = u16_at(body, pos)?;
+            pos += 2;
+            let e_bytes = (e_bits + 7) / 8;
+            let e = slice(body, pos, pos + e_bytes)?;
+            let key = RsaPublicKey::new(
+                rsa::BigUint::from_bytes_be(n),
+                rsa::BigUint::from_bytes_be(e),
+            )
+            .map_err(|_| PgpError::Packet("invalid RSA modulus/exponent"))?;
+            KeyMaterial::Rsa(key)
+        }
+        other => return Err(PgpError::UnsupportedAlgorithm(other)),
+    };
+
+    let fingerprint = v4_fingerprint(body);
+    let mut key_id = [0u8; 8];
+    key_id.copy_from_slice(&fingerprint[12..20]);
+    Ok(PublicKey {
+        key_id,
+        fingerprint,
+        material,
+    })
+}
+
+/// RFC 4880 §12.2: SHA-1 over a synthetic packet header (0x99, 2-byte
+/// length) followed by the public key packet body.
+fn v4_fingerprint(body: &[u8]) -> [u8; 20] {
+    use sha1::{Digest as _, Sha1};
+    let mut hasher = Sha1::new();
+    hasher.update([0x99]);
+    hasher.update((body.len() as u16).to_be_bytes());
+    hasher.update(body);
+    hasher.finalize().into()
+}
+
+struct DetachedSignature {
+    issuer_key_id: [u8; 8],
+    hash_algo: u8,
+    pubkey_algo: u8,
+    /// Seconds since the Unix epoch, from the mandatory creation-time
+    /// hashed subpacket. `None` if the signature omits it, which callers
+    /// should treat as untrustworthy metadata.
+    created: Option<u32>,
+    hashed_suffix: Vec<u8>,
+    left16: [u8; 2],
+    signature_bytes: Vec<u8>,
+}
+
+fn parse_signature_packet(body: &[u8]) -> Result<DetachedSignature, PgpError> {
+    if body.is_empty() || body[0] != 4 {
+        return Err(PgpError::Packet("only v4 signatures are supported"));
+    }
+    let pubkey_algo = byte(body, 2)?;
+    let hash_algo = byte(body, 3)?;
+    let hashed_len = u16_at(body, 4)?;
+    let hashed_subpackets_start = 6;
+    let hashed_subpackets_end = hashed_subpackets_start + hashed_len;
+    let hashed_subpackets = slice(body, hashed_subpackets_start, hashed_subpackets_end)?;
+
+    let unhashed_len_pos = hashed_subpackets_end;
+    let unhashed_len = u16_at(body, unhashed_len_pos)?;
+    let unhashed_start = unhashed_len_pos + 2;
+    let unhashed_subpackets = slice(body, unhashed_start, unhashed_start + unhashed_len)?;
+
+    let issuer_key_id = find_issuer_subpacket(hashed_subpackets)
+        .or_else(|| find_issuer_subpacket(unhashed_subpackets))
+        .ok_or(PgpError::Packet("no issuer key ID subpacket"))?;
+    // Per RFC 4880 §5.2.3.4 the creation-time subpacket MUST be hashed, so
+    // unlike the issuer key ID we don't also fall back to the unhashed area.
+    let created = find_creation_time_subpacket(hashed_subpackets);
+
+    // The "hashed suffix" covers everything up to and including the hashed
+    // subpacket data, plus the version+length trailer per RFC 4880 §5.2.4.
+    let mut hashed_suffix = slice(body, 0, hashed_subpackets_end)?.to_vec();
+    hashed_suffix.extend_from_slice(&[4, 0xff]);
+    hashed_suffix.extend_from_slice(&(hashed_subpackets_end as u32).to_be_bytes());
+
+    let left16_pos = unhashed_start + unhashed_len;
+    let left16_bytes = slice(body, left16_pos, left16_pos + 2)?;
+    let left16 = [left16_bytes[0], left16_bytes[1]];
+    let signature_bytes = slice(body, left16_pos + 2, body.len())?.to_vec();
+
+    Ok(DetachedSignature {
+        issuer_key_id,
+        hash_algo,
+        pubkey_algo,
+        created,
+        hashed_suffix,
+        left16,
+        signature_bytes,
+    })
+}
+
+const SUBPACKET_CREATION_TIME: u8 = 2;
+const SUBPACKET_ISSUER: u8 = 16;
+
+/// Walk an RFC 4880 §5.2.3.1 subpacket area and return the body of the
+/// first subpacket matching `wanted_type`, if any.
+fn find_subpacket(subpackets: &[u8], wanted_type: u8) -> Option<&[u8]> {
+    let mut pos = 0usize;
+    while pos < subpackets.len() {
+        let len = *subpackets.get(pos)? as usize;
+        pos += 1;
+        if len == 0 || pos + len > subpackets.len() {
+            break;
+        }
+        let sub_type = *subpackets.get(pos)?;
+        let sub_body = subpackets.get(pos + 1..pos + len)?;
+        if sub_type == wanted_type {
+            return Some(sub_body);
+        }
+        pos += len;
+    }
+    None
+}
+
+fn find_issuer_subpacket(subpackets: &[u8]) -> Option<[u8; 8]> {
+    let sub_body = find_subpacket(subpackets, SUBPACKET_ISSUER)?;
+    let sub_body: [u8; 8] = sub_body.try_into().ok()?;
+    Some(sub_body)
+}
+
+fn find_creation_time_subpacket(subpackets: &[u8]) -> Option<u32> {
+    let sub_body = find_subpacket(subpackets, SUBPACKET_CREATION_TIME)?;
+    let sub_body: [u8; 4] = sub_body.try_into().ok()?;
+    Some(u32::from_be_bytes(sub_body))
+}
+
+/// Allowed clock skew for creation-time validation: a signature dated up to
+/// this far in the future of our local clock is still accepted.
+const CREATION_TIME_SKEW_SECS: u32 = 300;
+
+fn check_creation_time(created: Option<u32>) -> Result<(), PgpError> {
+    let created = created.ok_or(PgpError::MissingCreationTime)?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as u32)
+        .unwrap_or(0);
+    if created > now.saturating_add(CREATION_TIME_SKEW_SECS) {
+        return Err(PgpError::FutureDated { created, now });
+    }
+    Ok(())
+}
+
+/// Verify a detached signature over `data` against the keys in `keyring`,
+/// returning the verifying key's fingerprint as a lowercase hex string.
+pub fn verify_detached(
+    data: &[u8],
+    signature_armored: &str,
+    keyring: &Keyring,
+) -> Result<String, PgpError> {
+    let sig_packets = dearmor_blocks(signature_armored, "PGP SIGNATURE")?;
+    let packets = read_packets(&sig_packets[0])?;
+    let sig_packet = packets
+        .iter()
+        .find(|p| p.tag == TAG_SIGNATURE)
+        .ok_or(PgpError::Packet("no signature packet found"))?;
+    let sig = parse_signature_packet(&sig_packet.body)?;
+
+    let key = keyring.find(&sig.issuer_key_id).ok_or(PgpError::UnknownIssuer)?;
+
+    if sig.hash_algo != 8 {
+        // 8 == SHA-256; this reader only implements that digest.
+        return Err(PgpError::UnsupportedAlgorithm(sig.hash_algo));
+    }
+
+    check_creation_time(sig.created)?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hasher.update(&sig.hashed_suffix);
+    let digest = hasher.finalize();
+
+    if digest[..2] != sig.left16 {
+        return Err(PgpError::BadSignature);
+    }
+
+    let ok = match (&key.material, sig.pubkey_algo) {
+        (KeyMaterial::Ed25519(vk), ALGO_EDDSA) => {
+            if sig.signature_bytes.len() != 64 {
+                return Err(PgpError::Packet("unexpected Ed25519 signature length"));
+            }
+            let mut sig_bytes = [0u8; 64];
+            sig_bytes.copy_from_slice(&sig.signature_bytes);
+            vk.verify(&digest, &EdSignature::from_bytes(&sig_bytes)).is_ok()
+        }
+        (KeyMaterial::Rsa(pk), ALGO_RSA_ENCRYPT_SIGN) => {
+            // `digest` is already the SHA-256 hash the signer signed over,
+            // so this must verify against the prehash directly rather than
+            // through `Verifier::verify`, which would hash it a second time.
+            let vk = RsaVerifyingKey::<Sha256>::new(pk.clone());
+            let signature = RsaSignature::try_from(sig.signature_bytes.as_slice())
+                .map_err(|_| PgpError::Packet("invalid RSA signature encoding"))?;
+            vk.verify_prehash(&digest, &signature).is_ok()
+        }
+        _ => return Err(PgpError::UnsupportedAlgorithm(sig.pubkey_algo)),
+    };
+
+    if !ok {
+        return Err(PgpError::BadSignature);
+    }
+
+    Ok(hex::encode(key.fingerprint))
+}
+
+/// Produce a detached, armored Ed25519 signature over `data`.
+pub fn sign_detached(data: &[u8], secret_key: &ed25519_dalek::SigningKey, created: u32) -> String {
+    use ed25519_dalek::Signer;
+    let verifying_key = secret_key.verifying_key();
+    let key_packet = build_ed25519_public_key_packet(&verifying_key);
+    let fingerprint = v4_fingerprint(&key_packet);
+    let mut key_id = [0u8; 8];
+    key_id.copy_from_slice(&fingerprint[12..20]);
+
+    let mut hashed_subpackets = Vec::new();
+    hashed_subpackets.extend_from_slice(&[5, 2, 0, 0, 0, 0]);
+    hashed_subpackets[2..6].copy_from_slice(&created.to_be_bytes());
+
+    let mut prefix = vec![4u8, 0x00, ALGO_EDDSA, 8];
+    prefix.extend_from_slice(&(hashed_subpackets.len() as u16).to_be_bytes());
+    prefix.extend_from_slice(&hashed_subpackets);
+
+    let mut hashed_suffix = prefix.clone();
+    hashed_suffix.extend_from_slice(&[4, 0xff]);
+    hashed_suffix.extend_from_slice(&(prefix.len() as u32).to_be_bytes());
+
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hasher.update(&hashed_suffix);
+    let digest = hasher.finalize();
+
+    let signature = secret_key.sign(&digest);
+
+    let mut unhashed = vec![9u8, SUBPACKET_ISSUER];
+    unhashed.extend_from_slice(&key_id);
+
+    let mut body = prefix;
+    body.extend_from_slice(&(unhashed.len() as u16).to_be_bytes());
+    body.extend_from_slice(&unhashed);
+    body.extend_from_slice(&digest[..2]);
+    body.extend_from_slice(&signature.to_bytes());
+
+    let mut packet = vec![0x88 | (TAG_SIGNATURE << 2), body.len() as u8];
+    packet.extend_from_slice(&body);
+
+    armor("PGP SIGNATURE", &packet)
+}
+
+fn build_ed25519_public_key_packet(vk: &VerifyingKey) -> Vec<u8> {
+    let mut body = vec![4u8, 0, 0, 0, 0, ALGO_EDDSA];
+    let curve_oid: &[u8] = &[0x2b, 0x06, 0x01, 0x04, 0x01, 0xda, 0x47, 0x0f, 0x01];
+    body.push(curve_oid.len() as u8);
+    body.extend_from_slice(curve_oid);
+    let mut point = vec![0x40u8];
+    point.extend_from_slice(vk.as_bytes());
+    body.extend_from_slice(&((point.len() * 8) as u16).to_be_bytes());
+    body.extend_from_slice(&point);
+    body
+}
+
+fn armor(label: &str, data: &[u8]) -> String {
+    use base64::Engine;
+    let b64 = base64::engine::general_purpose::STANDARD.encode(data);
+    let mut out = format!("-----BEGIN {label}-----\n\n");
+    for line in b64.as_bytes().chunks(64) {
+        out.push_str(std::str::from_utf8(line).expect("base64 is ASCII"));
+        out.push('\n');
+    }
+    out.push_str(&format!("-----END {label}-----\n"));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::SigningKey;
+
+    fn armored_keyring_for(signing_key: &SigningKey) -> String {
+        let key_body = build_ed25519_public_key_packet(&signing_key.verifying_key());
+        assert!(key_body.len() < 192, "test key packet must fit a 1-byte length");
+        let mut packet = vec![0xC0 | TAG_PUBLIC_KEY, key_body.len() as u8];
+        packet.extend_from_slice(&key_body);
+        armor("PGP PUBLIC KEY BLOCK", &packet)
+    }
+
+    fn now() -> u32 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as u32
+    }
+
+    #[test]
+    fn sign_then_verify_round_trip() {
+        let signing_key = SigningKey::generate(&mut rand_core::OsRng);
+        let data = b"a plugin archive that must not be tampered with";
+        let signature = sign_detached(data, &signing_key, now());
+        let keyring = Keyring::from_armored(&armored_keyring_for(&signing_key)).unwrap();
+
+        let fingerprint = verify_detached(data, &signature, &keyring).unwrap();
+        assert_eq!(fingerprint.len(), 40, "fingerprint should be 20 bytes of hex");
+    }
+
+    #[test]
+    fn tampered_data_is_rejected() {
+        let signing_key = SigningKey::generate(&mut rand_core::OsRng);
+        let signature = sign_detached(b"original contents", &signing_key, now());
+        let keyring = Keyring::from_armored(&armored_keyring_for(&signing_key)).unwrap();
+
+        let err = verify_detached(b"tampered contents", &signature, &keyring).unwrap_err();
+        assert!(matches!(err, PgpError::BadSignature));
+    }
+
+    #[test]
+    fn future_dated_signature_is_rejected() {
+        let signing_key = SigningKey::generate(&mut rand_core::OsRng);
+        let data = b"plugin archive";
+        let signature = sign_detached(data, &signing_key, now() + 10_000);
+        let keyring = Keyring::from_armored(&armored_keyring_for(&signing_key)).unwrap();
+
+        let err = verify_detached(data, &signature, &keyring).unwrap_err();
+        assert!(matches!(err, PgpError::FutureDated { .. }));
+    }
+
+    #[test]
+    fn unknown_signer_is_rejected() {
+        let signing_key = SigningKey::generate(&mut rand_core::OsRng);
+        let other_key = SigningKey::generate(&mut rand_core::OsRng);
+        let data = b"plugin archive";
+        let signature = sign_detached(data, &signing_key, now());
+
+        // Keyring only contains a different key, so the issuer can't be found.
+        let keyring = Keyring::from_armored(&armored_keyring_for(&other_key)).unwrap();
+        let err = verify_detached(data, &signature, &keyring).unwrap_err();
+        assert!(matches!(err, PgpError::UnknownIssuer));
+    }
+}
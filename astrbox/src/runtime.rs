@@ -0,0 +1,121 @@
+//! Async message-pipeline bridge: a multi-threaded Tokio executor that fans
+//! inbound bot events out to `async def` Python handlers with bounded
+//! backpressure, so the scheduling work happens in Rust instead of
+//! contending with Python's own event loop.
+//!
+//! The GIL is released for the entire time an event sits in the bounded
+//! channel or waits on the concurrency semaphore, and is only re-acquired
+//! to actually call into the Python handler and to resolve the awaitable
+//! we handed back to the caller.
+
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use pyo3_async_runtimes::tokio::future_into_py;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+use tokio::sync::{mpsc, oneshot, Semaphore};
+
+struct Job {
+    event: Py<PyAny>,
+    reply: oneshot::Sender<PyResult<Py<PyAny>>>,
+}
+
+/// Owns the Tokio executor, the inbound event queue, and the
+/// concurrency-limiting semaphore for one pipeline.
+pub struct Pipeline {
+    runtime: Runtime,
+    sender: mpsc::Sender<Job>,
+    semaphore: Arc<Semaphore>,
+    concurrency: u32,
+}
+
+impl Pipeline {
+    /// Spawn the executor plus a dispatcher task that pulls events off the
+    /// bounded channel and runs at most `concurrency` handlers at once.
+    pub fn spawn(handler: Py<PyAny>, concurrency: usize, queue_depth: usize) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()?;
+        let (sender, mut receiver) = mpsc::channel::<Job>(queue_depth);
+        let semaphore = Arc::new(Semaphore::new(concurrency));
+        let dispatch_semaphore = semaphore.clone();
+
+        runtime.spawn(async move {
+            while let Some(job) = receiver.recv().await {
+                let semaphore = dispatch_semaphore.clone();
+                let handler = Python::attach(|py| handler.clone_ref(py));
+                tokio::spawn(async move {
+                    let _permit = semaphore.acquire_owned().await;
+                    let outcome = run_handler(handler, job.event).await;
+                    let _ = job.reply.send(outcome);
+                });
+            }
+        });
+
+        Ok(Pipeline {
+            runtime,
+            sender,
+            semaphore,
+            concurrency: concurrency as u32,
+        })
+    }
+
+    /// Push `event` into the bounded queue and return a Python awaitable
+    /// that resolves once a handler has processed it.
+    pub fn submit(&self, py: Python<'_>, event: Py<PyAny>) -> PyResult<Py<PyAny>> {
+        let sender = self.sender.clone();
+        let _guard = self.runtime.enter();
+        future_into_py(py, async move {
+            let (reply_tx, reply_rx) = oneshot::channel();
+            sender
+                .send(Job {
+                    event,
+                    reply: reply_tx,
+                })
+                .await
+                .map_err(|_| PyRuntimeError::new_err("pipeline has been shut down"))?;
+            reply_rx
+                .await
+                .map_err(|_| PyRuntimeError::new_err("handler task dropped its reply"))?
+        })
+        .map(|bound| bound.unbind())
+    }
+
+    /// Stop accepting new events and wait for in-flight handlers to finish.
+    ///
+    /// Every handler task holds a semaphore permit for its entire lifetime,
+    /// so reclaiming all `concurrency` permits is equivalent to waiting for
+    /// every in-flight handler to complete; only then do we tear the
+    /// executor down. Handler tasks need to re-acquire the GIL (via
+    /// `Python::attach`) before they can call the Python coroutine and
+    /// release their permit, so the wait must run with the GIL released —
+    /// otherwise a handler still in flight can never attach and this
+    /// deadlocks forever.
+    pub fn shutdown(self, py: Python<'_>) {
+        let Pipeline {
+            runtime,
+            sender,
+            semaphore,
+            concurrency,
+        } = self;
+        drop(sender);
+        py.allow_threads(|| {
+            runtime.block_on(async move {
+                let _ = semaphore.acquire_many(concurrency).await;
+            });
+            runtime.shutdown_timeout(std::time::Duration::from_secs(30));
+        });
+    }
+}
+
+/// Release the GIL while waiting for the semaphore permit and channel
+/// round-trip already happened in the caller; here we just need to
+/// re-acquire it to call the `async def` handler and await its coroutine.
+async fn run_handler(handler: Py<PyAny>, event: Py<PyAny>) -> PyResult<Py<PyAny>> {
+    let coroutine = Python::attach(|py| -> PyResult<Py<PyAny>> {
+        let awaitable = handler.call1(py, (event,))?;
+        Ok(awaitable)
+    })?;
+    let result = Python::attach(|py| pyo3_async_runtimes::tokio::into_future(coroutine.bind(py).clone()))?;
+    result.await
+}